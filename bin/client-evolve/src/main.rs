@@ -1,6 +1,7 @@
 #![no_main]
 sp1_zkvm::entrypoint!(main);
 
+use alloy_primitives::B256;
 use rsp_client_executor::{
     executor::{EvolveClientExecutor, DESERIALZE_INPUTS},
     io::{CommittedHeader, EvolveClientExecutorInput},
@@ -17,6 +18,7 @@ pub fn main() {
 
     // Execute the block with evolve configuration.
     let chain_spec = Arc::new((&input.genesis).try_into().unwrap());
+    let genesis_hash = chain_spec.genesis_hash();
     let executor = EvolveClientExecutor::evolve(
         chain_spec,
         input.custom_beneficiary,
@@ -24,6 +26,10 @@ pub fn main() {
     );
     let header = executor.execute(input).expect("failed to execute client");
 
-    // Commit the block hash.
+    // Commit the block header and the genesis hash of the chain spec it was executed
+    // against. For `Genesis::Custom` that genesis block is built from the chain config
+    // over default genesis values (no alloc), so its hash reflects the fork schedule
+    // rather than the chain's real genesis block.
     sp1_zkvm::io::commit::<CommittedHeader>(&header.into());
+    sp1_zkvm::io::commit::<B256>(&genesis_hash);
 }
@@ -3,16 +3,9 @@ use std::{
     str::FromStr,
 };
 
-use alloy_eips::{eip7840::BlobParams, BlobScheduleBlobParams};
 use alloy_genesis::ChainConfig;
-use alloy_primitives::Address;
-use reth_chainspec::{
-    holesky::{HOLESKY_BPO1_TIMESTAMP, HOLESKY_BPO2_TIMESTAMP},
-    mainnet::{MAINNET_BPO1_TIMESTAMP, MAINNET_BPO2_TIMESTAMP},
-    sepolia::{SEPOLIA_BPO1_TIMESTAMP, SEPOLIA_BPO2_TIMESTAMP},
-    BaseFeeParams, BaseFeeParamsKind, Chain, ChainSpec, EthereumHardfork,
-    MAINNET_PRUNE_DELETE_LIMIT,
-};
+use alloy_primitives::{Address, B256, KECCAK_EMPTY};
+use reth_chainspec::{BaseFeeParams, BaseFeeParamsKind, ChainSpec};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
@@ -27,6 +20,7 @@ pub const OP_SEPOLIA_GENESIS_JSON: &str = include_str!("../../../bin/host/genesi
 pub enum Genesis {
     Mainnet,
     OpMainnet,
+    OpSepolia,
     Sepolia,
     Holesky,
     Linea,
@@ -38,6 +32,7 @@ impl Hash for Genesis {
         match self {
             Genesis::Mainnet => 1.hash(state),
             Genesis::OpMainnet => 10.hash(state),
+            Genesis::OpSepolia => 11155420.hash(state),
             Genesis::Sepolia => 11155111.hash(state),
             Genesis::Holesky => 17000.hash(state),
             Genesis::Linea => 59144.hash(state),
@@ -50,26 +45,86 @@ impl Hash for Genesis {
 }
 
 /// Configuration for Evolve-specific EVM features parsed from genesis.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+///
+/// Deserialization is hand-rolled (see the `Deserialize` impl below) so that an
+/// `evolve` section still using the old `mintAdmin`/`mintPrecompileActivationHeight`
+/// keys (removed in favor of `builtins`) is rejected with an error instead of parsing
+/// with the mint precompile config dropped. `from_chain_config` (and so `from_genesis`)
+/// turns that error into `None`.
+#[derive(Debug, Clone, Serialize, Default)]
 pub struct EvolveConfig {
-    /// Address to redirect base fees to (instead of burning).
+    /// Address to credit with `base_fee_per_gas * gas_used` in place of the EIP-1559
+    /// burn, as reported by [`EvolveConfig::base_fee_redirect`]. Nothing in this crate
+    /// applies that credit to state; the base fee is burned as before.
     #[serde(rename = "baseFeeSink")]
     pub base_fee_sink: Option<Address>,
-    /// Block height at which base fee redirect activates.
+    /// Block height at which the base fee redirect to `base_fee_sink` activates; below
+    /// this height the standard EIP-1559 burn applies.
     #[serde(rename = "baseFeeRedirectActivationHeight")]
     pub base_fee_redirect_activation_height: Option<u64>,
-    /// Admin address for the mint precompile.
-    #[serde(rename = "mintAdmin")]
-    pub mint_admin: Option<Address>,
-    /// Block height at which mint precompile activates.
-    #[serde(rename = "mintPrecompileActivationHeight")]
-    pub mint_precompile_activation_height: Option<u64>,
+    /// Custom precompiles/builtins attached to this chain, each with its own address,
+    /// pricing and activation height.
+    #[serde(default, rename = "builtins")]
+    pub builtins: Vec<BuiltinEntry>,
     /// Custom contract code size limit in bytes.
     #[serde(rename = "contractSizeLimit")]
     pub contract_size_limit: Option<usize>,
     /// Block height at which custom contract size limit activates.
     #[serde(rename = "contractSizeLimitActivationHeight")]
     pub contract_size_limit_activation_height: Option<u64>,
+    /// Block height from which [`EvolveConfig::eip3607_violation`] reports a sender
+    /// account whose `code_hash != KECCAK_EMPTY` as violating EIP-3607. Nothing in this
+    /// crate rejects transactions on this basis.
+    #[serde(rename = "eip3607ActivationHeight")]
+    pub eip3607_activation_height: Option<u64>,
+}
+
+impl<'de> Deserialize<'de> for EvolveConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            #[serde(rename = "baseFeeSink")]
+            base_fee_sink: Option<Address>,
+            #[serde(rename = "baseFeeRedirectActivationHeight")]
+            base_fee_redirect_activation_height: Option<u64>,
+            #[serde(default, rename = "builtins")]
+            builtins: Vec<BuiltinEntry>,
+            #[serde(rename = "contractSizeLimit")]
+            contract_size_limit: Option<usize>,
+            #[serde(rename = "contractSizeLimitActivationHeight")]
+            contract_size_limit_activation_height: Option<u64>,
+            #[serde(rename = "eip3607ActivationHeight")]
+            eip3607_activation_height: Option<u64>,
+            // Removed in favor of `builtins`; kept here only so we can detect and
+            // reject a genesis still written against the old schema instead of
+            // silently dropping the mint precompile config.
+            #[serde(rename = "mintAdmin")]
+            mint_admin: Option<Address>,
+            #[serde(rename = "mintPrecompileActivationHeight")]
+            mint_precompile_activation_height: Option<u64>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        if raw.mint_admin.is_some() || raw.mint_precompile_activation_height.is_some() {
+            return Err(serde::de::Error::custom(
+                "evolve config: `mintAdmin`/`mintPrecompileActivationHeight` were replaced by \
+                 the `builtins` registry; migrate to a `builtins` entry with \
+                 `\"kind\": \"mint\"` instead",
+            ));
+        }
+
+        Ok(EvolveConfig {
+            base_fee_sink: raw.base_fee_sink,
+            base_fee_redirect_activation_height: raw.base_fee_redirect_activation_height,
+            builtins: raw.builtins,
+            contract_size_limit: raw.contract_size_limit,
+            contract_size_limit_activation_height: raw.contract_size_limit_activation_height,
+            eip3607_activation_height: raw.eip3607_activation_height,
+        })
+    }
 }
 
 impl EvolveConfig {
@@ -90,6 +145,188 @@ impl EvolveConfig {
             _ => None,
         }
     }
+
+    /// Returns the builtins active at `height`, i.e. those whose `activation_height`
+    /// has been reached, keyed by address so the executor can layer them directly on
+    /// top of the standard precompile set (`standard_set.extend(active_builtins)`).
+    ///
+    /// INCOMPLETE: nothing in this repository calls this yet. Declaring a `builtins`
+    /// entry in genesis has no effect on block execution until `EvolveClientExecutor`,
+    /// in the `rsp_client_executor` crate (not present in this tree), assembles the
+    /// active precompile set from this for a block, as the request requires.
+    pub fn active_builtins(
+        &self,
+        height: u64,
+    ) -> std::collections::BTreeMap<Address, &BuiltinEntry> {
+        self.builtins
+            .iter()
+            .filter(|entry| height >= entry.activation_height())
+            .map(|entry| (entry.address(), entry))
+            .collect()
+    }
+
+    /// Returns the address the base fee burn should be credited to instead, and the
+    /// amount to credit it (`base_fee_per_gas * gas_used`), if the redirect is active
+    /// at `height`. Returns `None` below the activation height, or if no sink is
+    /// configured, in which case the standard EIP-1559 burn applies.
+    ///
+    /// INCOMPLETE: nothing in this repository calls this yet. Redirecting the burn is
+    /// only real once `EvolveClientExecutor::evolve`, in the `rsp_client_executor`
+    /// crate (not present in this tree), calls this per transaction and credits the
+    /// result before the final state root is committed. Until that wiring lands, a
+    /// genesis with `baseFeeSink` set still has its base fee burned exactly as before.
+    pub fn base_fee_redirect(
+        &self,
+        height: u64,
+        base_fee_per_gas: u128,
+        gas_used: u64,
+    ) -> Option<(Address, u128)> {
+        let sink = self.base_fee_sink?;
+        let activation_height = self.base_fee_redirect_activation_height?;
+        if height < activation_height {
+            return None;
+        }
+
+        Some((sink, base_fee_per_gas.saturating_mul(gas_used as u128)))
+    }
+
+    /// Returns `true` if a transaction from an account with `code_hash` should be
+    /// rejected under EIP-3607 at the given `height`. Below the activation height, or
+    /// if EIP-3607 isn't configured, nothing is rejected on this basis.
+    ///
+    /// INCOMPLETE: nothing in this repository calls this yet. No transaction is
+    /// actually rejected until `EvolveClientExecutor::execute`, in the
+    /// `rsp_client_executor` crate (not present in this tree), checks this per
+    /// transaction during pre-execution validation, as the request requires.
+    pub fn eip3607_violation(&self, height: u64, code_hash: B256) -> bool {
+        match self.eip3607_activation_height {
+            Some(activation_height) => height >= activation_height && code_hash != KECCAK_EMPTY,
+            None => false,
+        }
+    }
+}
+
+/// A single precompile/builtin attached at `address` from `activation_height` onward,
+/// mirroring OpenEthereum's spec `builtin` blocks. Tagged flatly by `kind` rather than
+/// nesting a separate kind object, so genesis JSON reads as
+/// `{"address": "0x...", "kind": "mint", "admin": "0x...", "pricing": {...},
+/// "activationHeight": 0}`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum BuiltinEntry {
+    /// Mints native token balance, authorized by `admin`.
+    Mint {
+        /// Address the builtin is attached at.
+        address: Address,
+        /// Address authorized to trigger mints.
+        admin: Address,
+        /// Gas pricing for calls into this builtin.
+        pricing: BuiltinPricing,
+        /// Block height at which this builtin becomes active.
+        #[serde(rename = "activationHeight")]
+        activation_height: u64,
+    },
+    /// Identity precompile (copies input to output).
+    Identity {
+        /// Address the builtin is attached at.
+        address: Address,
+        /// Gas pricing for calls into this builtin.
+        pricing: BuiltinPricing,
+        /// Block height at which this builtin becomes active.
+        #[serde(rename = "activationHeight")]
+        activation_height: u64,
+    },
+    /// Big-integer modular exponentiation precompile.
+    Modexp {
+        /// Address the builtin is attached at.
+        address: Address,
+        /// Gas pricing for calls into this builtin.
+        pricing: BuiltinPricing,
+        /// Block height at which this builtin becomes active.
+        #[serde(rename = "activationHeight")]
+        activation_height: u64,
+    },
+    /// A named builtin resolved by the executor, for chain-specific extensions.
+    Custom {
+        /// Address the builtin is attached at.
+        address: Address,
+        /// Name the executor looks up to resolve the implementation.
+        name: String,
+        /// Gas pricing for calls into this builtin.
+        pricing: BuiltinPricing,
+        /// Block height at which this builtin becomes active.
+        #[serde(rename = "activationHeight")]
+        activation_height: u64,
+    },
+}
+
+impl BuiltinEntry {
+    /// Address this builtin is attached at.
+    pub fn address(&self) -> Address {
+        match self {
+            Self::Mint { address, .. }
+            | Self::Identity { address, .. }
+            | Self::Modexp { address, .. }
+            | Self::Custom { address, .. } => *address,
+        }
+    }
+
+    /// Block height at which this builtin becomes active.
+    pub fn activation_height(&self) -> u64 {
+        match self {
+            Self::Mint { activation_height, .. }
+            | Self::Identity { activation_height, .. }
+            | Self::Modexp { activation_height, .. }
+            | Self::Custom { activation_height, .. } => *activation_height,
+        }
+    }
+}
+
+/// Gas pricing descriptor for a [`BuiltinEntry`], mirroring OpenEthereum's
+/// `builtin.pricing` spec entries.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BuiltinPricing {
+    /// Gas cost of `base` plus `word` for each 32-byte word of input.
+    Linear {
+        /// Base gas cost charged for any call.
+        base: u64,
+        /// Additional gas cost per 32-byte input word.
+        word: u64,
+    },
+}
+
+/// Custom EIP-1559 base fee parameters parsed from a genesis' `extra_fields`, for
+/// chains whose elasticity multiplier or base fee max-change denominator deviate
+/// from the Ethereum defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BaseFeeConfig {
+    /// Bounds the base fee max change per block, i.e. `1 / denominator`.
+    #[serde(rename = "baseFeeChangeDenominator")]
+    pub base_fee_change_denominator: Option<u128>,
+    /// Divisor of the gas limit used to derive the target gas used per block.
+    #[serde(rename = "elasticityMultiplier")]
+    pub elasticity_multiplier: Option<u128>,
+}
+
+impl BaseFeeConfig {
+    /// Extracts base fee configuration from a ChainConfig's extra_fields.
+    /// Returns None if no "baseFeeConfig" section exists or if deserialization fails.
+    pub fn from_chain_config(config: &ChainConfig) -> Option<Self> {
+        config
+            .extra_fields
+            .get("baseFeeConfig")
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    /// Builds the effective [`BaseFeeParams`], falling back to `default` for any
+    /// field that wasn't overridden in genesis.
+    pub fn base_fee_params(&self, default: BaseFeeParams) -> BaseFeeParams {
+        BaseFeeParams::new(
+            self.base_fee_change_denominator.unwrap_or(default.max_change_denominator),
+            self.elasticity_multiplier.unwrap_or(default.elasticity_multiplier),
+        )
+    }
 }
 
 impl FromStr for Genesis {
@@ -113,6 +350,7 @@ impl TryFrom<u64> for Genesis {
         match value {
             1 => Ok(Genesis::Mainnet),
             10 => Ok(Genesis::OpMainnet),
+            11155420 => Ok(Genesis::OpSepolia),
             17000 => Ok(Genesis::Holesky),
             59144 => Ok(Genesis::Linea),
             11155111 => Ok(Genesis::Sepolia),
@@ -126,64 +364,28 @@ impl TryFrom<&Genesis> for ChainSpec {
 
     fn try_from(value: &Genesis) -> Result<Self, Self::Error> {
         match value {
-            Genesis::Mainnet => {
-                let mainnet = ChainSpec {
-                    chain: Chain::mainnet(),
-                    genesis: Default::default(),
-                    genesis_header: Default::default(),
-                    paris_block_and_final_difficulty: Default::default(),
-                    hardforks: EthereumHardfork::mainnet().into(),
-                    deposit_contract: Default::default(),
-                    base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-                    prune_delete_limit: MAINNET_PRUNE_DELETE_LIMIT,
-                    blob_params: BlobScheduleBlobParams::default().with_scheduled([
-                        (MAINNET_BPO1_TIMESTAMP, BlobParams::bpo1()),
-                        (MAINNET_BPO2_TIMESTAMP, BlobParams::bpo2()),
-                    ]),
-                };
-
-                Ok(mainnet)
-            }
-            Genesis::Sepolia => {
-                let sepolia = ChainSpec {
-                    chain: Chain::sepolia(),
-                    genesis: Default::default(),
-                    genesis_header: Default::default(),
-                    paris_block_and_final_difficulty: Default::default(),
-                    hardforks: EthereumHardfork::sepolia().into(),
-                    deposit_contract: Default::default(),
-                    base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-                    prune_delete_limit: 10000,
-                    blob_params: BlobScheduleBlobParams::default().with_scheduled([
-                        (SEPOLIA_BPO1_TIMESTAMP, BlobParams::bpo1()),
-                        (SEPOLIA_BPO2_TIMESTAMP, BlobParams::bpo2()),
-                    ]),
-                };
-                Ok(sepolia)
-            }
-            Genesis::Holesky => {
-                let holesky = ChainSpec {
-                    chain: Chain::holesky(),
-                    genesis: Default::default(),
-                    genesis_header: Default::default(),
-                    paris_block_and_final_difficulty: Default::default(),
-                    hardforks: EthereumHardfork::holesky().into(),
-                    deposit_contract: Default::default(),
-                    base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
-                    prune_delete_limit: 10000,
-                    blob_params: BlobScheduleBlobParams::default().with_scheduled([
-                        (HOLESKY_BPO1_TIMESTAMP, BlobParams::bpo1()),
-                        (HOLESKY_BPO2_TIMESTAMP, BlobParams::bpo2()),
-                    ]),
-                };
-                Ok(holesky)
-            }
-            Genesis::OpMainnet => Err(ChainSpecError::InvalidConversion),
+            // Cloned from reth's canonical statics rather than hand-assembled, so that
+            // `genesis`/`genesis_header` (and therefore `genesis_hash()`) reflect the
+            // real network genesis instead of `Default::default()`.
+            Genesis::Mainnet => Ok(reth_chainspec::MAINNET.as_ref().clone()),
+            Genesis::Sepolia => Ok(reth_chainspec::SEPOLIA.as_ref().clone()),
+            Genesis::Holesky => Ok(reth_chainspec::HOLESKY.as_ref().clone()),
+            Genesis::OpMainnet | Genesis::OpSepolia => Err(ChainSpecError::InvalidConversion),
             Genesis::Linea => Ok(ChainSpec::from_genesis(genesis_from_json(LINEA_GENESIS_JSON)?)),
-            Genesis::Custom(config) => Ok(ChainSpec::from_genesis(alloy_genesis::Genesis {
-                config: config.clone(),
-                ..Default::default()
-            })),
+            Genesis::Custom(config) => {
+                let mut chain_spec = ChainSpec::from_genesis(alloy_genesis::Genesis {
+                    config: config.clone(),
+                    ..Default::default()
+                });
+
+                if let Some(base_fee_config) = BaseFeeConfig::from_chain_config(config) {
+                    chain_spec.base_fee_params = BaseFeeParamsKind::Constant(
+                        base_fee_config.base_fee_params(BaseFeeParams::ethereum()),
+                    );
+                }
+
+                Ok(chain_spec)
+            }
         }
     }
 }
@@ -194,39 +396,30 @@ impl TryFrom<&Genesis> for reth_optimism_chainspec::OpChainSpec {
 
     fn try_from(value: &Genesis) -> Result<Self, Self::Error> {
         match value {
-            Genesis::OpMainnet => {
-                use reth_chainspec::Hardfork;
-                use reth_optimism_forks::OpHardfork;
-
-                let op_mainnet = reth_optimism_chainspec::OpChainSpec {
-                    inner: ChainSpec {
-                        chain: Chain::optimism_mainnet(),
-                        genesis: Default::default(),
-                        genesis_header: Default::default(),
-                        paris_block_and_final_difficulty: Default::default(),
-                        hardforks: reth_optimism_forks::OP_MAINNET_HARDFORKS.clone(),
-                        deposit_contract: Default::default(),
-                        base_fee_params: BaseFeeParamsKind::Variable(
-                            vec![
-                                (EthereumHardfork::London.boxed(), BaseFeeParams::optimism()),
-                                (OpHardfork::Canyon.boxed(), BaseFeeParams::optimism_canyon()),
-                            ]
-                            .into(),
-                        ),
-                        prune_delete_limit: 10000,
-                        blob_params: Default::default(),
-                    },
-                };
-
-                Ok(op_mainnet)
-            }
+            // Cloned from reth-optimism's canonical static rather than hand-assembled,
+            // so that `genesis`/`genesis_header` (and therefore `genesis_hash()`)
+            // reflect the real network genesis instead of `Default::default()`.
+            Genesis::OpMainnet => Ok(reth_optimism_chainspec::OP_MAINNET.as_ref().clone()),
+            // No canonical static is available for OP Sepolia, so build it from the
+            // embedded genesis JSON instead, the same way `Genesis::Linea` does above -
+            // this derives `genesis`/`genesis_header`/`hardforks` from real data rather
+            // than a hand-maintained hardfork schedule over a default genesis.
+            Genesis::OpSepolia => Ok(reth_optimism_chainspec::OpChainSpec::from_genesis(
+                genesis_from_json(OP_SEPOLIA_GENESIS_JSON)?,
+            )),
             Genesis::Custom(config) => {
-                let custom =
+                let mut custom =
                     reth_optimism_chainspec::OpChainSpec::from_genesis(alloy_genesis::Genesis {
                         config: config.clone(),
                         ..Default::default()
                     });
 
+                if let Some(base_fee_config) = BaseFeeConfig::from_chain_config(config) {
+                    custom.inner.base_fee_params = BaseFeeParamsKind::Constant(
+                        base_fee_config.base_fee_params(BaseFeeParams::ethereum()),
+                    );
+                }
+
                 Ok(custom)
             }
             _ => Err(ChainSpecError::InvalidConversion),
@@ -238,8 +431,59 @@ impl TryFrom<&Genesis> for reth_optimism_chainspec::OpChainSpec {
 mod tests {
 
     use alloy_eips::eip7840::BlobParams;
+    use alloy_primitives::{address, B256, KECCAK_EMPTY};
+
+    use reth_chainspec::BaseFeeParams;
+
+    use crate::genesis::{
+        genesis_from_json, BaseFeeConfig, BuiltinEntry, BuiltinPricing, EvolveConfig, Genesis,
+        OP_SEPOLIA_GENESIS_JSON,
+    };
+
+    #[test]
+    fn test_evolve_config_rejects_legacy_mint_keys() {
+        let json = r#"{"mintAdmin": "0x000000000000000000000000000000000000dEaD"}"#;
+        let err = serde_json::from_str::<EvolveConfig>(json).unwrap_err();
+        assert!(err.to_string().contains("builtins"));
+    }
+
+    #[test]
+    fn test_base_fee_params_full_override() {
+        let config = BaseFeeConfig {
+            base_fee_change_denominator: Some(250),
+            elasticity_multiplier: Some(6),
+        };
+
+        let params = config.base_fee_params(BaseFeeParams::ethereum());
+        assert_eq!(params.max_change_denominator, 250);
+        assert_eq!(params.elasticity_multiplier, 6);
+    }
+
+    #[test]
+    fn test_base_fee_params_partial_override_falls_back_to_default() {
+        let default = BaseFeeParams::ethereum();
+        let config = BaseFeeConfig {
+            base_fee_change_denominator: Some(250),
+            elasticity_multiplier: None,
+        };
 
-    use crate::genesis::{genesis_from_json, Genesis, OP_SEPOLIA_GENESIS_JSON};
+        let params = config.base_fee_params(default);
+        assert_eq!(params.max_change_denominator, 250);
+        assert_eq!(params.elasticity_multiplier, default.elasticity_multiplier);
+    }
+
+    #[test]
+    fn test_base_fee_params_no_override_matches_default() {
+        let default = BaseFeeParams::ethereum();
+        let config = BaseFeeConfig::default();
+
+        assert_eq!(config.base_fee_params(default), default);
+    }
+
+    #[test]
+    fn test_op_sepolia_chain_id_roundtrip() {
+        assert_eq!(Genesis::try_from(11155420u64).unwrap(), Genesis::OpSepolia);
+    }
 
     #[test]
     fn test_custom_genesis_bincode_roundtrip() {
@@ -274,4 +518,91 @@ mod tests {
 
         assert_eq!(genesis, deserialized);
     }
+
+    #[test]
+    fn test_base_fee_redirect_below_activation_height() {
+        let config = EvolveConfig {
+            base_fee_sink: Some(address!("0x000000000000000000000000000000000000dEaD")),
+            base_fee_redirect_activation_height: Some(100),
+            ..Default::default()
+        };
+
+        assert_eq!(config.base_fee_redirect(99, 10, 21000), None);
+    }
+
+    #[test]
+    fn test_base_fee_redirect_at_and_after_activation_height() {
+        let sink = address!("0x000000000000000000000000000000000000dEaD");
+        let config = EvolveConfig {
+            base_fee_sink: Some(sink),
+            base_fee_redirect_activation_height: Some(100),
+            ..Default::default()
+        };
+
+        assert_eq!(config.base_fee_redirect(100, 10, 21000), Some((sink, 210000)));
+        assert_eq!(config.base_fee_redirect(101, 10, 21000), Some((sink, 210000)));
+    }
+
+    #[test]
+    fn test_base_fee_redirect_without_sink_configured() {
+        let config = EvolveConfig {
+            base_fee_redirect_activation_height: Some(100),
+            ..Default::default()
+        };
+
+        assert_eq!(config.base_fee_redirect(200, 10, 21000), None);
+    }
+
+    #[test]
+    fn test_eip3607_violation_below_activation_height() {
+        let config = EvolveConfig { eip3607_activation_height: Some(100), ..Default::default() };
+
+        assert!(!config.eip3607_violation(99, B256::repeat_byte(1)));
+    }
+
+    #[test]
+    fn test_eip3607_violation_at_and_after_activation_height() {
+        let config = EvolveConfig { eip3607_activation_height: Some(100), ..Default::default() };
+
+        assert!(config.eip3607_violation(100, B256::repeat_byte(1)));
+        assert!(config.eip3607_violation(101, B256::repeat_byte(1)));
+        assert!(!config.eip3607_violation(100, KECCAK_EMPTY));
+    }
+
+    #[test]
+    fn test_eip3607_violation_without_activation_height_configured() {
+        let config = EvolveConfig::default();
+
+        assert!(!config.eip3607_violation(1_000_000, B256::repeat_byte(1)));
+    }
+
+    #[test]
+    fn test_active_builtins_activation_height_boundary() {
+        let identity = address!("0x000000000000000000000000000000000000dEaD");
+        let modexp = address!("0x00000000000000000000000000000000000000Fe");
+        let config = EvolveConfig {
+            builtins: vec![
+                BuiltinEntry::Identity {
+                    address: identity,
+                    pricing: BuiltinPricing::Linear { base: 15, word: 0 },
+                    activation_height: 100,
+                },
+                BuiltinEntry::Modexp {
+                    address: modexp,
+                    pricing: BuiltinPricing::Linear { base: 200, word: 0 },
+                    activation_height: 200,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let active = config.active_builtins(100);
+        assert_eq!(active.len(), 1);
+        assert!(active.contains_key(&identity));
+
+        let active = config.active_builtins(200);
+        assert_eq!(active.len(), 2);
+        assert!(active.contains_key(&identity));
+        assert!(active.contains_key(&modexp));
+    }
 }